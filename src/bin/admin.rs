@@ -0,0 +1,12 @@
+//! Admin command.
+//!
+//! Lets an operator manage categories, tags and users directly against the
+//! database, without needing an authenticated HTTP session.
+//!
+//! You can execute it with: `cargo run --bin admin -- <subcommand>`
+use torrust_index_backend::console::commands::admin::run_admin;
+
+#[tokio::main]
+async fn main() {
+    run_admin().await;
+}
@@ -11,22 +11,43 @@ use std::sync::Once;
 use tracing::info;
 use tracing::level_filters::LevelFilter;
 
-use crate::config::v1::LogLevel;
+use crate::config::v1::{LogFormat, LogLevel};
 
 static INIT: Once = Once::new();
 
-pub fn setup(log_level: &Option<LogLevel>) {
+/// Generates a correlation id to tag all the log events produced while
+/// handling a single request, so they can be grouped and filtered in log
+/// aggregation.
+#[must_use]
+pub fn new_correlation_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+pub fn setup(log_level: &Option<LogLevel>, log_format: &Option<LogFormat>) {
     let tracing_level = config_level_or_default(log_level);
 
     if tracing_level == LevelFilter::OFF {
         return;
     }
 
+    let style = config_style_or_default(log_format);
+
     INIT.call_once(|| {
-        tracing_stdout_init(tracing_level, &TraceStyle::Default);
+        tracing_stdout_init(tracing_level, &style);
     });
 }
 
+fn config_style_or_default(log_format: &Option<LogFormat>) -> TraceStyle {
+    match log_format {
+        None => TraceStyle::Default,
+        Some(format) => match format {
+            LogFormat::Default => TraceStyle::Default,
+            LogFormat::Compact => TraceStyle::Compact,
+            LogFormat::Json => TraceStyle::Json,
+        },
+    }
+}
+
 fn config_level_or_default(log_level: &Option<LogLevel>) -> LevelFilter {
     match log_level {
         None => LevelFilter::INFO,
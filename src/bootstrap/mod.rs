@@ -0,0 +1,8 @@
+pub mod logging;
+
+use crate::config::v1::Settings;
+
+/// Initializes process-wide bootstrapping concerns, currently just logging.
+pub fn init(settings: &Settings) {
+    logging::setup(&settings.log_level, &settings.log_format);
+}
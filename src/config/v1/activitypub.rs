@@ -0,0 +1,48 @@
+//! ActivityPub federation configuration.
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the optional ActivityPub federation subsystem.
+///
+/// When `enabled` is `false` (the default) the index behaves exactly as it
+/// did before federation support was added: no actor endpoint, no outbox,
+/// and no delivery of activities to followers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActivityPub {
+    /// Whether the federation subsystem is active.
+    pub enabled: bool,
+    /// The preferred username of the actor representing this index, eg:
+    /// `index` in `https://example.com/activitypub/actor/index`.
+    pub actor_name: String,
+    /// A short human readable summary shown on the actor's profile.
+    pub actor_summary: String,
+    /// Maximum number of activities returned by the outbox in one page.
+    pub outbox_page_size: u64,
+    /// Activities older than this are rejected by the inbox, to limit
+    /// replay of stale signed requests. Expressed in seconds.
+    pub signature_max_age_secs: u64,
+}
+
+impl Default for ActivityPub {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            actor_name: "index".to_string(),
+            actor_summary: "A Torrust Index instance.".to_string(),
+            outbox_page_size: 20,
+            signature_max_age_secs: 30,
+        }
+    }
+}
+
+/// The RSA keypair used to sign outgoing activities and to identify the
+/// index's actor to remote servers.
+///
+/// The public key is served from the actor endpoint so that remote servers
+/// can verify `Signature` headers on activities we deliver to their inbox.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ActorKeyPair {
+    /// PEM encoded RSA private key used to sign outgoing activities.
+    pub private_key_pem: String,
+    /// PEM encoded RSA public key, published on the actor endpoint.
+    pub public_key_pem: String,
+}
@@ -0,0 +1,40 @@
+//! Authentication configuration.
+use serde::{Deserialize, Serialize};
+
+use super::activitypub::ActorKeyPair;
+
+/// Authentication configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Auth {
+    /// Secret key used to sign JSON Web Tokens issued to users.
+    pub secret_key: SecretKey,
+    /// The keypair used to sign outgoing ActivityPub activities and to
+    /// identify the index's actor, when federation is enabled.
+    pub activitypub_keypair: ActorKeyPair,
+}
+
+impl Default for Auth {
+    fn default() -> Self {
+        Self {
+            secret_key: SecretKey::new("MaxVerstappenWC2021"),
+            activitypub_keypair: ActorKeyPair::default(),
+        }
+    }
+}
+
+impl Auth {
+    pub fn override_secret_key(&mut self, secret_key: &str) {
+        self.secret_key = SecretKey::new(secret_key);
+    }
+}
+
+/// A secret key, eg: used for signing JSON Web Tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SecretKey(String);
+
+impl SecretKey {
+    #[must_use]
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
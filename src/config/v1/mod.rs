@@ -1,3 +1,4 @@
+pub mod activitypub;
 pub mod api;
 pub mod auth;
 pub mod database;
@@ -10,6 +11,7 @@ pub mod website;
 
 use serde::{Deserialize, Serialize};
 
+use self::activitypub::ActivityPub;
 use self::api::Api;
 use self::auth::{Auth, SecretKey};
 use self::database::Database;
@@ -27,6 +29,10 @@ pub struct Settings {
     /// Logging level. Possible values are: `Off`, `Error`, `Warn`, `Info`,
     /// `Debug` and `Trace`. Default is `Info`.
     pub log_level: Option<LogLevel>,
+    /// Logging output format. Possible values are: `Default`, `Compact` and
+    /// `Json`. Default is `Default`. Use `Json` to feed logs into a log
+    /// aggregator that groups and filters by structured fields.
+    pub log_format: Option<LogFormat>,
     /// The website customizable values.
     pub website: Website,
     /// The tracker configuration.
@@ -45,6 +51,8 @@ pub struct Settings {
     pub api: Api,
     /// The tracker statistics importer job configuration.
     pub tracker_statistics_importer: TrackerStatisticsImporter,
+    /// The ActivityPub federation configuration. Disabled by default.
+    pub activitypub: ActivityPub,
 }
 
 impl Settings {
@@ -63,6 +71,7 @@ impl Settings {
         }
         "***".clone_into(&mut self.mail.password);
         self.auth.secret_key = SecretKey::new("***");
+        "***".clone_into(&mut self.auth.activitypub_keypair.private_key_pem);
     }
 }
 
@@ -88,3 +97,14 @@ pub enum LogLevel {
     /// Corresponds to the `Trace` log level.
     Trace,
 }
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human readable, single line per event.
+    Default,
+    /// Human readable, condensed.
+    Compact,
+    /// One JSON object per event, for log aggregators.
+    Json,
+}
@@ -0,0 +1,164 @@
+//! Admin CLI command.
+//!
+//! Lets an operator manage categories, tags and users directly against the
+//! database, bypassing the `administrator` check the HTTP API enforces.
+//! This is what makes it possible to bootstrap the first admin user and
+//! seed categories before the web API is reachable.
+//!
+//! You can execute it with: `cargo run --bin admin -- <subcommand>`
+use std::sync::Arc;
+
+use clap::{Parser, Subcommand};
+
+use crate::bootstrap::config::init_configuration;
+use crate::databases::database::{self, Database};
+use crate::services::category::DbCategoryRepository;
+use crate::services::user::DbUserRepository;
+
+#[derive(Parser)]
+#[command(name = "admin", about = "Torrust Index administration commands")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Manage torrent categories.
+    Category {
+        #[command(subcommand)]
+        action: CategoryAction,
+    },
+    /// Manage torrent tags.
+    Tag {
+        #[command(subcommand)]
+        action: TagAction,
+    },
+    /// Manage user accounts.
+    User {
+        #[command(subcommand)]
+        action: UserAction,
+    },
+    /// Print the active settings.
+    Settings {
+        #[command(subcommand)]
+        action: SettingsAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CategoryAction {
+    Add { name: String },
+    Delete { name: String },
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum TagAction {
+    Add { name: String },
+    Delete { name: String },
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum UserAction {
+    Promote { user_id: i64 },
+    Demote { user_id: i64 },
+    List,
+}
+
+#[derive(Subcommand)]
+pub enum SettingsAction {
+    Dump,
+}
+
+/// Runs the admin CLI.
+///
+/// # Panics
+///
+/// It panics if the configuration cannot be loaded or the database
+/// connection cannot be established.
+pub async fn run_admin() {
+    let cli = Cli::parse();
+    let configuration = init_configuration().await;
+    let settings = configuration.settings.read().await;
+    let database: Arc<Box<dyn Database>> = Arc::new(
+        database::connect(&settings.database.connect_url.to_string())
+            .await
+            .expect("Could not connect to database."),
+    );
+    drop(settings);
+
+    let category_repository = DbCategoryRepository::new(database.clone());
+    let user_repository = DbUserRepository::new(database.clone());
+
+    match cli.command {
+        Command::Category { action } => match action {
+            CategoryAction::Add { name } => {
+                category_repository
+                    .add_category(&name)
+                    .await
+                    .expect("Failed to add category.");
+                println!("Category \"{name}\" added.");
+            }
+            CategoryAction::Delete { name } => {
+                category_repository
+                    .delete_category(&name)
+                    .await
+                    .expect("Failed to delete category.");
+                println!("Category \"{name}\" deleted.");
+            }
+            CategoryAction::List => {
+                let categories = category_repository.get_categories().await.expect("Failed to list categories.");
+                for category in categories {
+                    println!("{}: {}", category.category_id, category.name);
+                }
+            }
+        },
+        Command::Tag { action } => match action {
+            TagAction::Add { name } => {
+                database.insert_tag_and_get_id(&name).await.expect("Failed to add tag.");
+                println!("Tag \"{name}\" added.");
+            }
+            TagAction::Delete { name } => {
+                database.delete_tag(&name).await.expect("Failed to delete tag.");
+                println!("Tag \"{name}\" deleted.");
+            }
+            TagAction::List => {
+                let tags = database.get_tags().await.expect("Failed to list tags.");
+                for tag in tags {
+                    println!("{}: {}", tag.tag_id, tag.name);
+                }
+            }
+        },
+        Command::User { action } => match action {
+            UserAction::Promote { user_id } => {
+                user_repository
+                    .grant_admin_role(&user_id)
+                    .await
+                    .expect("Failed to promote user.");
+                println!("User {user_id} promoted to administrator.");
+            }
+            UserAction::Demote { user_id } => {
+                user_repository
+                    .revoke_admin_role(&user_id)
+                    .await
+                    .expect("Failed to demote user.");
+                println!("User {user_id} demoted.");
+            }
+            UserAction::List => {
+                let users = database.get_users().await.expect("Failed to list users.");
+                for user in users {
+                    println!("{}: {} (administrator: {})", user.user_id, user.username, user.administrator);
+                }
+            }
+        },
+        Command::Settings { action } => match action {
+            SettingsAction::Dump => {
+                let mut settings = configuration.get_all().await;
+                settings.remove_secrets();
+                println!("{}", toml::to_string_pretty(&settings).expect("Failed to serialize settings."));
+            }
+        },
+    }
+}
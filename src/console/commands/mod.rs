@@ -0,0 +1,2 @@
+pub mod admin;
+pub mod import_tracker_statistics;
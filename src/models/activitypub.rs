@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+/// An ActivityPub actor representing this index instance.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Actor {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    #[serde(rename = "preferredUsername")]
+    pub preferred_username: String,
+    pub summary: String,
+    pub inbox: String,
+    pub outbox: String,
+    #[serde(rename = "publicKey")]
+    pub public_key: PublicKey,
+}
+
+/// The public key block published on an actor, per the `security-v1`
+/// extension used by HTTP Signatures.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PublicKey {
+    pub id: String,
+    pub owner: String,
+    #[serde(rename = "publicKeyPem")]
+    pub public_key_pem: String,
+}
+
+/// A `Create` activity wrapping a `Note` that announces a newly published
+/// torrent to followers.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CreateNoteActivity {
+    #[serde(rename = "@context")]
+    pub context: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    pub actor: String,
+    pub object: Note,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Note {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub note_type: String,
+    #[serde(rename = "attributedTo")]
+    pub attributed_to: String,
+    pub content: String,
+    pub url: String,
+    pub published: String,
+}
+
+/// A remote actor that follows this index's actor, eg: another index or a
+/// Fediverse account. Delivery of outgoing activities iterates over these.
+#[derive(Debug, Serialize, Deserialize, Clone, sqlx::FromRow)]
+pub struct Follower {
+    pub follower_id: i64,
+    pub actor_url: String,
+    pub inbox_url: String,
+}
+
+/// The subset of an incoming activity this index needs in order to react to
+/// it: `Follow` activities add a follower, `Undo` activities remove one.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InboxActivity {
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    pub actor: String,
+    #[serde(default)]
+    pub object: Option<serde_json::Value>,
+}
+
+/// A resolved WebFinger result for `acct:name@domain` lookups.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebFingerResource {
+    pub subject: String,
+    pub links: Vec<WebFingerLink>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebFingerLink {
+    pub rel: String,
+    #[serde(rename = "type")]
+    pub media_type: String,
+    pub href: String,
+}
@@ -0,0 +1,394 @@
+//! ActivityPub federation service.
+//!
+//! Builds and signs outgoing activities (draft-cavage HTTP Signatures) so
+//! that a torrent published on this index can be announced to followers on
+//! the Fediverse, and verifies the same signature scheme on activities
+//! delivered to this instance's inbox.
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::{Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+use tracing::{error, instrument};
+
+use crate::config::v1::activitypub::ActorKeyPair;
+use crate::config::Configuration;
+use crate::databases::database::Database;
+use crate::errors::ServiceError;
+use crate::models::activitypub::{
+    Actor, CreateNoteActivity, Follower, InboxActivity, Note, PublicKey, WebFingerLink, WebFingerResource,
+};
+
+pub struct Service {
+    configuration: Arc<Configuration>,
+    followers: Arc<DbFollowerRepository>,
+    http_client: reqwest::Client,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(configuration: Arc<Configuration>, followers: Arc<DbFollowerRepository>) -> Service {
+        Service {
+            configuration,
+            followers,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Builds the actor document served at the index's actor endpoint.
+    pub async fn get_actor(&self) -> Actor {
+        let settings = self.configuration.settings.read().await;
+        let base_url = settings.net.base_url.clone().unwrap_or_default().to_string();
+        let actor_id = format!("{base_url}/activitypub/actor/{}", settings.activitypub.actor_name);
+
+        Actor {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: actor_id.clone(),
+            actor_type: "Service".to_string(),
+            preferred_username: settings.activitypub.actor_name.clone(),
+            summary: settings.activitypub.actor_summary.clone(),
+            inbox: format!("{actor_id}/inbox"),
+            outbox: format!("{actor_id}/outbox"),
+            public_key: PublicKey {
+                id: format!("{actor_id}#main-key"),
+                owner: actor_id,
+                public_key_pem: settings.auth.activitypub_keypair.public_key_pem.clone(),
+            },
+        }
+    }
+
+    /// Builds the `Create`/`Note` activity announcing a newly published
+    /// torrent.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the index's actor url cannot be resolved.
+    pub async fn build_torrent_announcement(
+        &self,
+        torrent_id: i64,
+        title: &str,
+        published_at: &str,
+    ) -> Result<CreateNoteActivity, ServiceError> {
+        let settings = self.configuration.settings.read().await;
+        let Some(base_url) = settings.net.base_url.clone() else {
+            return Err(ServiceError::InternalServerError);
+        };
+        let base_url = base_url.to_string();
+        let actor_id = format!("{base_url}/activitypub/actor/{}", settings.activitypub.actor_name);
+        let torrent_url = format!("{base_url}/torrent/{torrent_id}");
+
+        Ok(CreateNoteActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{torrent_url}/activity"),
+            activity_type: "Create".to_string(),
+            actor: actor_id.clone(),
+            object: Note {
+                id: torrent_url.clone(),
+                note_type: "Note".to_string(),
+                attributed_to: actor_id,
+                content: format!("New torrent published: {title}"),
+                url: torrent_url,
+                published: published_at.to_string(),
+            },
+        })
+    }
+
+    /// Builds the WebFinger response for `acct:<actor_name>@<domain>`, so
+    /// remote servers can discover this index's actor url.
+    pub async fn resolve_webfinger(&self, resource: &str) -> Option<WebFingerResource> {
+        let settings = self.configuration.settings.read().await;
+        let base_url = settings.net.base_url.clone().unwrap_or_default().to_string();
+        let domain = base_url.trim_start_matches("https://").trim_start_matches("http://");
+        let expected = format!("acct:{}@{domain}", settings.activitypub.actor_name);
+
+        if resource != expected {
+            return None;
+        }
+
+        let actor_id = format!("{base_url}/activitypub/actor/{}", settings.activitypub.actor_name);
+
+        Some(WebFingerResource {
+            subject: resource.to_string(),
+            links: vec![WebFingerLink {
+                rel: "self".to_string(),
+                media_type: "application/activity+json".to_string(),
+                href: actor_id,
+            }],
+        })
+    }
+
+    /// Returns a page of this actor's recent activities for the outbox.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the activities cannot be loaded from the
+    /// database.
+    pub async fn get_outbox_page(&self) -> Result<Vec<CreateNoteActivity>, ServiceError> {
+        let page_size = self.configuration.settings.read().await.activitypub.outbox_page_size;
+
+        self.followers
+            .database
+            .get_recent_torrent_announcements(page_size)
+            .await
+            .map_err(|_| ServiceError::InternalServerError)
+    }
+
+    /// Handles an activity delivered to the index's inbox: `Follow`
+    /// activities add the sender as a follower, `Undo` activities remove
+    /// them. The caller must have already verified the request's
+    /// `Signature` header with [`Service::verify_inbox_request`].
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the activity cannot be persisted.
+    pub async fn handle_inbox_activity(&self, activity: &InboxActivity, sender_inbox_url: &str) -> Result<(), ServiceError> {
+        match activity.activity_type.as_str() {
+            "Follow" => self.followers.add_follower(&activity.actor, sender_inbox_url).await,
+            "Undo" => self.followers.remove_follower(&activity.actor).await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Builds the announcement for a newly published torrent and delivers
+    /// it, signed, to every follower's inbox.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the announcement cannot be built.
+    #[instrument(skip(self))]
+    pub async fn publish_torrent_announcement(
+        &self,
+        torrent_id: i64,
+        title: &str,
+        published_at: &str,
+    ) -> Result<(), ServiceError> {
+        if !self.configuration.settings.read().await.activitypub.enabled {
+            return Ok(());
+        }
+
+        let activity = self.build_torrent_announcement(torrent_id, title, published_at).await?;
+        let followers = self.followers.list_followers().await.map_err(|_| ServiceError::DatabaseError)?;
+
+        for follower in followers {
+            self.deliver_activity(&activity, &follower).await;
+        }
+
+        Ok(())
+    }
+
+    /// Signs and delivers `activity` to a single follower's inbox,
+    /// logging, but not failing the caller on, delivery errors.
+    async fn deliver_activity(&self, activity: &CreateNoteActivity, follower: &Follower) {
+        let settings = self.configuration.settings.read().await;
+        let base_url = settings.net.base_url.clone().unwrap_or_default().to_string();
+        let actor_id = format!("{base_url}/activitypub/actor/{}", settings.activitypub.actor_name);
+        let keypair = settings.auth.activitypub_keypair.clone();
+        drop(settings);
+
+        let Ok(body) = serde_json::to_vec(activity) else {
+            error!(follower_inbox = %follower.inbox_url, "failed to serialize activity for delivery");
+            return;
+        };
+
+        let Ok(inbox_url) = url::Url::parse(&follower.inbox_url) else {
+            error!(follower_inbox = %follower.inbox_url, "follower inbox url is not valid");
+            return;
+        };
+
+        let host = inbox_url.host_str().unwrap_or_default().to_string();
+        let date = httpdate::fmt_http_date(SystemTime::now());
+        let digest = format!("SHA-256={}", BASE64.encode(Sha256::digest(&body)));
+
+        let Ok(signature) = Self::sign_request(&keypair, &actor_id, "POST", inbox_url.path(), &host, &date, &body) else {
+            error!(follower_inbox = %follower.inbox_url, "failed to sign outgoing activity");
+            return;
+        };
+
+        let response = self
+            .http_client
+            .post(inbox_url)
+            .header("Host", host)
+            .header("Date", date)
+            .header("Digest", digest)
+            .header("Content-Type", "application/activity+json")
+            .header("Signature", signature)
+            .body(body)
+            .send()
+            .await;
+
+        match response {
+            Ok(response) if response.status().is_success() => {}
+            Ok(response) => {
+                error!(follower_inbox = %follower.inbox_url, status = response.status().as_u16(), "follower inbox rejected activity");
+            }
+            Err(_) => {
+                error!(follower_inbox = %follower.inbox_url, "follower inbox is unreachable");
+            }
+        }
+    }
+
+    /// Signs an outgoing request body as described by draft-cavage HTTP
+    /// Signatures and returns the `Signature` header value.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the configured private key is invalid or if
+    /// signing fails.
+    pub fn sign_request(
+        keypair: &ActorKeyPair,
+        actor_url: &str,
+        method: &str,
+        path: &str,
+        host: &str,
+        date: &str,
+        body: &[u8],
+    ) -> Result<String, ServiceError> {
+        let digest = format!("SHA-256={}", BASE64.encode(Sha256::digest(body)));
+        let signing_string = format!(
+            "(request-target): {} {path}\nhost: {host}\ndate: {date}\ndigest: {digest}",
+            method.to_lowercase()
+        );
+
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&keypair.private_key_pem)
+            .map_err(|_| ServiceError::InternalServerError)?;
+
+        let hashed = Sha256::digest(signing_string.as_bytes());
+        let signature = private_key
+            .sign(Pkcs1v15Sign::new::<Sha256>(), &hashed)
+            .map_err(|_| ServiceError::InternalServerError)?;
+
+        Ok(format!(
+            "keyId=\"{actor_url}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{}\"",
+            BASE64.encode(signature)
+        ))
+    }
+
+    /// Fetches and returns `actor_url`'s `Actor` document, so an incoming
+    /// activity's signature can be verified against the sender's actual key
+    /// and its real `inbox` can be used for future delivery.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the actor cannot be fetched or parsed.
+    pub async fn fetch_remote_actor(&self, actor_url: &str) -> Result<Actor, ServiceError> {
+        let response = self
+            .http_client
+            .get(actor_url)
+            .header("Accept", "application/activity+json")
+            .send()
+            .await
+            .map_err(|_| ServiceError::InternalServerError)?;
+
+        response.json::<Actor>().await.map_err(|_| ServiceError::InternalServerError)
+    }
+
+    /// Whether the federation subsystem is active. Every route handler must
+    /// check this before serving the actor, outbox, WebFinger, or inbox.
+    pub async fn is_enabled(&self) -> bool {
+        self.configuration.settings.read().await.activitypub.enabled
+    }
+
+    /// Verifies the `Signature` header on an incoming inbox request.
+    ///
+    /// # Errors
+    ///
+    /// It returns [`ServiceError::Unauthorized`] if the signature is
+    /// missing, malformed, stale (older than the configured
+    /// `signature_max_age_secs`), or does not match the sender's public key.
+    pub async fn verify_inbox_request(
+        &self,
+        sender_public_key_pem: &str,
+        method: &str,
+        path: &str,
+        host: &str,
+        date: &str,
+        body: &[u8],
+        signature_b64: &str,
+    ) -> Result<(), ServiceError> {
+        self.check_date_skew(date).await?;
+
+        let digest = format!("SHA-256={}", BASE64.encode(Sha256::digest(body)));
+        let signing_string = format!(
+            "(request-target): {} {path}\nhost: {host}\ndate: {date}\ndigest: {digest}",
+            method.to_lowercase()
+        );
+
+        let public_key =
+            RsaPublicKey::from_public_key_pem(sender_public_key_pem).map_err(|_| ServiceError::Unauthorized)?;
+        let signature = BASE64.decode(signature_b64).map_err(|_| ServiceError::Unauthorized)?;
+        let hashed = Sha256::digest(signing_string.as_bytes());
+
+        public_key
+            .verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, &signature)
+            .map_err(|_| ServiceError::Unauthorized)
+    }
+
+    /// Rejects a `Date` header that is more than `signature_max_age_secs`
+    /// old, to limit replay of captured signed requests.
+    async fn check_date_skew(&self, date: &str) -> Result<(), ServiceError> {
+        let max_age_secs = self.configuration.settings.read().await.activitypub.signature_max_age_secs;
+
+        let received = httpdate::parse_http_date(date).map_err(|_| ServiceError::Unauthorized)?;
+        let now = SystemTime::now();
+        let age = now
+            .duration_since(received)
+            .unwrap_or_else(|_| received.duration_since(now).unwrap_or_default());
+
+        if age.as_secs() > max_age_secs {
+            return Err(ServiceError::Unauthorized);
+        }
+
+        Ok(())
+    }
+}
+
+/// Stores the remote actors that follow this index's actor.
+pub struct DbFollowerRepository {
+    database: Arc<Box<dyn Database>>,
+}
+
+impl DbFollowerRepository {
+    #[must_use]
+    pub fn new(database: Arc<Box<dyn Database>>) -> Self {
+        Self { database }
+    }
+
+    /// Adds `actor_url` as a follower, or is a no-op if it already follows.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn add_follower(&self, actor_url: &str, inbox_url: &str) -> Result<(), ServiceError> {
+        self.database
+            .add_activitypub_follower(actor_url, inbox_url)
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+
+    /// Removes `actor_url` from the follower list.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn remove_follower(&self, actor_url: &str) -> Result<(), ServiceError> {
+        self.database
+            .remove_activitypub_follower(actor_url)
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+
+    /// Lists every known follower.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn list_followers(&self) -> Result<Vec<Follower>, ServiceError> {
+        self.database
+            .get_activitypub_followers()
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+}
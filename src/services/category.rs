@@ -1,6 +1,9 @@
 //! Category service.
 use std::sync::Arc;
 
+use tracing::instrument;
+
+use super::sanitization::{self, SanitizationPolicy};
 use super::user::DbUserRepository;
 use crate::databases::database::{Category, Database, Error as DatabaseError};
 use crate::errors::ServiceError;
@@ -10,6 +13,7 @@ use crate::models::user::UserId;
 pub struct Service {
     category_repository: Arc<DbCategoryRepository>,
     user_repository: Arc<DbUserRepository>,
+    sanitization_policy: SanitizationPolicy,
 }
 
 impl Service {
@@ -18,6 +22,7 @@ impl Service {
         Service {
             category_repository,
             user_repository,
+            sanitization_policy: SanitizationPolicy::default(),
         }
     }
 
@@ -29,7 +34,8 @@ impl Service {
     ///
     /// * The user does not have the required permissions.
     /// * There is a database error.
-    pub async fn add_category(&self, category_name: &str, user_id: &UserId) -> Result<i64, ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id, user_id = %user_id))]
+    pub async fn add_category(&self, category_name: &str, user_id: &UserId, correlation_id: &str) -> Result<i64, ServiceError> {
         let user = self.user_repository.get_compact_user(user_id).await?;
 
         // Check if user is administrator
@@ -38,7 +44,9 @@ impl Service {
             return Err(ServiceError::Unauthorized);
         }
 
-        match self.category_repository.add_category(category_name).await {
+        let category_name = sanitization::clean(category_name, &self.sanitization_policy);
+
+        match self.category_repository.add_category(&category_name).await {
             Ok(id) => Ok(id),
             Err(e) => match e {
                 DatabaseError::CategoryAlreadyExists => Err(ServiceError::CategoryExists),
@@ -55,7 +63,8 @@ impl Service {
     ///
     /// * The user does not have the required permissions.
     /// * There is a database error.
-    pub async fn delete_category(&self, category_name: &str, user_id: &UserId) -> Result<(), ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id, user_id = %user_id))]
+    pub async fn delete_category(&self, category_name: &str, user_id: &UserId, correlation_id: &str) -> Result<(), ServiceError> {
         let user = self.user_repository.get_compact_user(user_id).await?;
 
         // Check if user is administrator
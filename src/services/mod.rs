@@ -1,8 +1,10 @@
 //! App services.
 pub mod about;
+pub mod activitypub;
 pub mod authentication;
 pub mod category;
 pub mod proxy;
+pub mod sanitization;
 pub mod settings;
 pub mod tag;
 pub mod torrent;
@@ -0,0 +1,52 @@
+//! HTML sanitization for user-submitted rich-text fields.
+//!
+//! `UserProfile::bio`, torrent descriptions, and comments are free text
+//! supplied by users and later rendered by front-ends, so they must be
+//! cleaned on write to prevent stored XSS. This module wraps an
+//! `ammonia`-style allowlist cleaner and is used by the `user`, `category`,
+//! and `torrent` services wherever they persist such fields.
+use ammonia::{Builder, UrlRelative};
+use serde::{Deserialize, Serialize};
+
+/// The set of tags a rich-text field is allowed to contain.
+///
+/// The struct is serializable, but it is not part of the settings: the
+/// `user`, `category` and `torrent` services each use
+/// `SanitizationPolicy::default()`. The attribute and URL rules are fixed in
+/// [`clean`] and are not part of the policy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SanitizationPolicy {
+    /// HTML tags left in place; everything else is stripped.
+    pub allowed_tags: Vec<String>,
+}
+
+impl Default for SanitizationPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_tags: [
+                "p", "a", "b", "i", "em", "strong", "ul", "ol", "li", "br", "code", "blockquote",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        }
+    }
+}
+
+/// Cleans `dirty` according to `policy`.
+///
+/// Strips every tag not in `policy.allowed_tags`, removes all `on*`
+/// attributes and `style`, forces `rel="noopener nofollow"` on links, and
+/// allows only `http`/`https` URL schemes on `<a href>`.
+#[must_use]
+pub fn clean(dirty: &str, policy: &SanitizationPolicy) -> String {
+    let allowed_tags: std::collections::HashSet<&str> = policy.allowed_tags.iter().map(String::as_str).collect();
+
+    Builder::default()
+        .tags(allowed_tags)
+        .link_rel(Some("noopener nofollow"))
+        .url_schemes(["http", "https"].into_iter().collect())
+        .url_relative(UrlRelative::Deny)
+        .clean(dirty)
+        .to_string()
+}
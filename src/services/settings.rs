@@ -1,6 +1,8 @@
 //! Settings service.
 use std::sync::Arc;
 
+use tracing::instrument;
+
 use super::user::DbUserRepository;
 use crate::config::{Configuration, ConfigurationPublic, TorrustIndex};
 use crate::errors::ServiceError;
@@ -25,7 +27,8 @@ impl Service {
     /// # Errors
     ///
     /// It returns an error if the user does not have the required permissions.
-    pub async fn get_all(&self, user_id: &UserId) -> Result<TorrustIndex, ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id, user_id = %user_id))]
+    pub async fn get_all(&self, user_id: &UserId, correlation_id: &str) -> Result<TorrustIndex, ServiceError> {
         let user = self.user_repository.get_compact(user_id).await?;
 
         // Check if user is administrator
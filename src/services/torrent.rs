@@ -0,0 +1,71 @@
+//! Torrent service.
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::activitypub;
+use super::sanitization::{self, SanitizationPolicy};
+use crate::databases::database::Database;
+use crate::errors::ServiceError;
+use crate::models::torrent::TorrentId;
+use crate::models::user::UserId;
+
+pub struct Service {
+    database: Arc<Box<dyn Database>>,
+    sanitization_policy: SanitizationPolicy,
+    activitypub_service: Arc<activitypub::Service>,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(database: Arc<Box<dyn Database>>, activitypub_service: Arc<activitypub::Service>) -> Service {
+        Service {
+            database,
+            sanitization_policy: SanitizationPolicy::default(),
+            activitypub_service,
+        }
+    }
+
+    /// Announces a newly published torrent to followers over ActivityPub,
+    /// when federation is enabled. It does not change the torrent itself.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    #[instrument(skip(self))]
+    pub async fn publish_torrent(&self, torrent_id: &TorrentId, title: &str, published_at: &str) -> Result<(), ServiceError> {
+        self.activitypub_service
+            .publish_torrent_announcement(*torrent_id, title, published_at)
+            .await
+    }
+
+    /// Updates a torrent's description.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    #[instrument(skip(self))]
+    pub async fn update_description(&self, torrent_id: &TorrentId, description: &str) -> Result<(), ServiceError> {
+        let description = sanitization::clean(description, &self.sanitization_policy);
+
+        self.database
+            .update_torrent_description(*torrent_id, &description)
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+
+    /// Adds a new comment to a torrent.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    #[instrument(skip(self))]
+    pub async fn add_comment(&self, torrent_id: &TorrentId, user_id: &UserId, comment: &str) -> Result<i64, ServiceError> {
+        let comment = sanitization::clean(comment, &self.sanitization_policy);
+
+        self.database
+            .insert_torrent_comment(*torrent_id, *user_id, &comment)
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+}
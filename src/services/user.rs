@@ -0,0 +1,99 @@
+//! User service.
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::sanitization::{self, SanitizationPolicy};
+use crate::databases::database::{Database, Error as DatabaseError};
+use crate::errors::ServiceError;
+use crate::models::user::{UserCompact, UserId};
+
+pub struct Service {
+    user_repository: Arc<DbUserRepository>,
+    sanitization_policy: SanitizationPolicy,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(user_repository: Arc<DbUserRepository>) -> Service {
+        Service {
+            user_repository,
+            sanitization_policy: SanitizationPolicy::default(),
+        }
+    }
+
+    /// Updates a user's public bio.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    #[instrument(skip(self))]
+    pub async fn update_bio(&self, user_id: &UserId, bio: &str) -> Result<(), ServiceError> {
+        let bio = sanitization::clean(bio, &self.sanitization_policy);
+
+        self.user_repository.set_bio(user_id, &bio).await
+    }
+}
+
+pub struct DbUserRepository {
+    database: Arc<Box<dyn Database>>,
+}
+
+impl DbUserRepository {
+    #[must_use]
+    pub fn new(database: Arc<Box<dyn Database>>) -> Self {
+        Self { database }
+    }
+
+    /// It gets the compact representation of a user.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the user does not exist.
+    pub async fn get_compact_user(&self, user_id: &UserId) -> Result<UserCompact, ServiceError> {
+        self.database
+            .get_user_compact(*user_id)
+            .await
+            .map_err(|_| ServiceError::UserNotFound)
+    }
+
+    /// It gets the compact representation of a user.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the user does not exist.
+    pub async fn get_compact(&self, user_id: &UserId) -> Result<UserCompact, ServiceError> {
+        self.get_compact_user(user_id).await
+    }
+
+    /// Grants the administrator role to a user.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn grant_admin_role(&self, user_id: &UserId) -> Result<(), DatabaseError> {
+        self.database.grant_admin_role(*user_id).await
+    }
+
+    /// Revokes the administrator role from a user.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn revoke_admin_role(&self, user_id: &UserId) -> Result<(), DatabaseError> {
+        self.database.revoke_admin_role(*user_id).await
+    }
+
+    /// Sets a user's bio. The caller is responsible for sanitizing `bio`
+    /// before it reaches this method.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if there is a database error.
+    pub async fn set_bio(&self, user_id: &UserId, bio: &str) -> Result<(), ServiceError> {
+        self.database
+            .set_user_bio(*user_id, bio)
+            .await
+            .map_err(|_| ServiceError::DatabaseError)
+    }
+}
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use hyper::StatusCode;
-use log::error;
 use serde::{Deserialize, Serialize};
+use tracing::{error, instrument};
 
 use super::api::{Client, ConnectionInfo};
 use crate::config::Configuration;
@@ -68,18 +68,24 @@ impl Service {
     ///
     /// Will return an error if the HTTP request failed (for example if the
     /// tracker API is offline) or if the tracker API returned an error.
-    pub async fn whitelist_info_hash(&self, info_hash: String) -> Result<(), ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id))]
+    pub async fn whitelist_info_hash(&self, info_hash: String, correlation_id: &str) -> Result<(), ServiceError> {
         let response = self.api_client.whitelist_torrent(&info_hash).await;
 
         match response {
             Ok(response) => {
-                if response.status().is_success() {
+                let status = response.status();
+                if status.is_success() {
                     Ok(())
                 } else {
+                    error!(info_hash, status = status.as_u16(), "failed to whitelist torrent on tracker");
                     Err(ServiceError::WhitelistingError)
                 }
             }
-            Err(_) => Err(ServiceError::TrackerOffline),
+            Err(_) => {
+                error!(info_hash, "tracker API is offline");
+                Err(ServiceError::TrackerOffline)
+            }
         }
     }
 
@@ -89,7 +95,8 @@ impl Service {
     ///
     /// Will return an error if the HTTP request failed (for example if the
     /// tracker API is offline) or if the tracker API returned an error.
-    pub async fn remove_info_hash_from_whitelist(&self, info_hash: String) -> Result<(), ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id))]
+    pub async fn remove_info_hash_from_whitelist(&self, info_hash: String, correlation_id: &str) -> Result<(), ServiceError> {
         let response = self.api_client.remove_torrent_from_whitelist(&info_hash).await;
 
         match response {
@@ -133,14 +140,17 @@ impl Service {
     ///
     /// Will return an error if the HTTP request to get torrent info fails or
     /// if the response cannot be parsed.
-    pub async fn get_torrent_info(&self, info_hash: &str) -> Result<TorrentInfo, ServiceError> {
+    #[instrument(skip(self), fields(correlation_id = %correlation_id))]
+    pub async fn get_torrent_info(&self, info_hash: &str, correlation_id: &str) -> Result<TorrentInfo, ServiceError> {
         let response = self
             .api_client
             .get_torrent_info(info_hash)
             .await
             .map_err(|_| ServiceError::InternalServerError)?;
 
-        if response.status() == StatusCode::NOT_FOUND {
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
             return Err(ServiceError::TorrentNotFound);
         }
 
@@ -157,11 +167,11 @@ impl Service {
             if let Ok(torrent_info) = torrent_info {
                 Ok(torrent_info)
             } else {
-                error!("Failed to parse torrent info from tracker response. Body: {}", body);
+                error!(info_hash, status = status.as_u16(), %body, "failed to parse torrent info from tracker response");
                 Err(ServiceError::InternalServerError)
             }
         } else {
-            error!("Tracker API response without body");
+            error!(info_hash, status = status.as_u16(), "tracker API response without body");
             Err(ServiceError::InternalServerError)
         }
     }
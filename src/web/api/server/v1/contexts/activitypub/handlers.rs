@@ -0,0 +1,113 @@
+//! ActivityPub federation handlers: actor profile, outbox, WebFinger and
+//! inbox.
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Deserialize;
+
+use crate::services::activitypub::Service;
+
+const ACTIVITY_JSON: &str = "application/activity+json";
+
+/// `GET /activitypub/actor/:name`
+pub async fn get_actor(State(service): State<Arc<Service>>) -> Response {
+    if !service.is_enabled().await {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let actor = service.get_actor().await;
+    ([("content-type", ACTIVITY_JSON)], Json(actor)).into_response()
+}
+
+/// `GET /activitypub/actor/:name/outbox`
+pub async fn get_outbox(State(service): State<Arc<Service>>) -> Response {
+    if !service.is_enabled().await {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    match service.get_outbox_page().await {
+        Ok(activities) => ([("content-type", ACTIVITY_JSON)], Json(activities)).into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebFingerQuery {
+    resource: String,
+}
+
+/// `GET /.well-known/webfinger?resource=acct:name@domain`
+pub async fn get_webfinger(State(service): State<Arc<Service>>, Query(query): Query<WebFingerQuery>) -> Response {
+    if !service.is_enabled().await {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    match service.resolve_webfinger(&query.resource).await {
+        Some(resource) => ([("content-type", "application/jrd+json")], Json(resource)).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// `POST /activitypub/actor/:name/inbox`
+///
+/// Verifies the request's `Signature` header before handing the activity to
+/// the service, so an unsigned or stale delivery never reaches it.
+pub async fn post_inbox(State(service): State<Arc<Service>>, uri: Uri, headers: HeaderMap, body: axum::body::Bytes) -> Response {
+    if !service.is_enabled().await {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let Ok(activity) = serde_json::from_slice::<crate::models::activitypub::InboxActivity>(&body) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let (Some(signature), Some(date), Some(host)) = (
+        headers.get("signature").and_then(|v| v.to_str().ok()),
+        headers.get("date").and_then(|v| v.to_str().ok()),
+        headers.get("host").and_then(|v| v.to_str().ok()),
+    ) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let Some(signature_b64) = extract_signature_param(signature, "signature") else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let Ok(sender_actor) = service.fetch_remote_actor(&activity.actor).await else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let verification = service
+        .verify_inbox_request(
+            &sender_actor.public_key.public_key_pem,
+            "POST",
+            uri.path(),
+            host,
+            date,
+            &body,
+            &signature_b64,
+        )
+        .await;
+
+    if verification.is_err() {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    match service.handle_inbox_activity(&activity, &sender_actor.inbox).await {
+        Ok(()) => StatusCode::ACCEPTED.into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Extracts a quoted `key="value"` parameter from a draft-cavage
+/// `Signature` header.
+fn extract_signature_param(header: &str, key: &str) -> Option<String> {
+    header.split(',').find_map(|part| {
+        let part = part.trim();
+        let prefix = format!("{key}=\"");
+        part.strip_prefix(&prefix)?.strip_suffix('"').map(str::to_string)
+    })
+}
@@ -0,0 +1,3 @@
+//! The `activitypub` context: actor profile, outbox, WebFinger and inbox.
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,18 @@
+//! Routes for the `activitypub` context.
+use std::sync::Arc;
+
+use axum::routing::{get, post};
+use axum::Router;
+
+use super::handlers::{get_actor, get_outbox, get_webfinger, post_inbox};
+use crate::services::activitypub::Service;
+
+/// Adds the actor, outbox, WebFinger and inbox routes to `router`.
+pub fn add(router: Router, activitypub_service: Arc<Service>) -> Router {
+    router
+        .route("/activitypub/actor/:name", get(get_actor))
+        .route("/activitypub/actor/:name/outbox", get(get_outbox))
+        .route("/activitypub/actor/:name/inbox", post(post_inbox))
+        .route("/.well-known/webfinger", get(get_webfinger))
+        .with_state(activitypub_service)
+}
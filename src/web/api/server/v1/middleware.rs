@@ -0,0 +1,30 @@
+//! Per-request correlation id middleware.
+//!
+//! Mints one correlation id per incoming request and makes it available to
+//! handlers via [`CorrelationId`], so every log event produced while
+//! handling that request - across however many services it touches - can be
+//! tied back together.
+use axum::extract::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::bootstrap::logging::new_correlation_id;
+
+/// A request-scoped correlation id, inserted into the request extensions by
+/// [`correlation_id`] and picked up by handlers with `Extension<CorrelationId>`.
+#[derive(Debug, Clone)]
+pub struct CorrelationId(pub String);
+
+impl std::fmt::Display for CorrelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Mints a correlation id for the request and inserts it into the request
+/// extensions before calling the next handler in the stack.
+pub async fn correlation_id(mut request: Request, next: Next) -> Response {
+    let correlation_id = CorrelationId(new_correlation_id());
+    request.extensions_mut().insert(correlation_id);
+    next.run(request).await
+}
@@ -0,0 +1,2 @@
+pub mod contexts;
+pub mod middleware;